@@ -0,0 +1,114 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+macro_rules! impl_async_stream_syslog_sender {
+    ($sender:ident, $stream:ident) => {
+        impl $sender {
+            /// Send a message with the given severity as defined in RFC-3164.
+            pub async fn send_rfc3164<M: std::fmt::Display>(
+                &mut self,
+                severity: $crate::Severity,
+                message: M,
+            ) -> std::io::Result<()> {
+                use tokio::io::AsyncWriteExt;
+                let message = self.context.format_rfc3164(severity, Some(message));
+                let framed = self.framing.frame(message.to_string().as_bytes());
+                self.$stream.write_all(&framed).await
+            }
+
+            /// Send a message with the given severity as defined in RFC-5424.
+            pub async fn send_rfc5424<S: Into<String>, M: std::fmt::Display>(
+                &mut self,
+                severity: $crate::Severity,
+                msgid: Option<S>,
+                elements: Vec<$crate::SDElement>,
+                message: M,
+            ) -> std::io::Result<()> {
+                use tokio::io::AsyncWriteExt;
+                let message = self
+                    .context
+                    .format_rfc5424(severity, msgid, elements, Some(message));
+                let framed = self.framing.frame(message.to_string().as_bytes());
+                self.$stream.write_all(&framed).await
+            }
+
+            /// Send a pre-formatted message.
+            pub async fn send_formatted(&mut self, formatted: &[u8]) -> std::io::Result<()> {
+                use tokio::io::AsyncWriteExt;
+                let framed = self.framing.frame(formatted);
+                self.$stream.write_all(&framed).await
+            }
+
+            /// Set the postfix appended to each message under non-transparent framing.
+            pub fn set_postfix(&mut self, postfix: impl Into<std::borrow::Cow<'static, str>>) {
+                self.framing = $crate::sender::Framing::NonTransparent(postfix.into());
+            }
+
+            /// Set how successive messages are delimited on the wire, per [RFC-6587].
+            ///
+            /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+            pub fn set_framing(&mut self, framing: $crate::sender::Framing) {
+                self.framing = framing;
+            }
+
+            /// Flush the writer.
+            pub async fn flush(&mut self) -> std::io::Result<()> {
+                use tokio::io::AsyncWriteExt;
+                self.$stream.flush().await
+            }
+        }
+    };
+}
+
+pub(crate) use impl_async_stream_syslog_sender;
+
+macro_rules! impl_async_datagram_syslog_sender {
+    ($sender:ident, $socket:ident) => {
+        impl $sender {
+            /// Send a message with the given severity as defined in RFC-3164.
+            pub async fn send_rfc3164<M: std::fmt::Display>(
+                &mut self,
+                severity: $crate::Severity,
+                message: M,
+            ) -> std::io::Result<()> {
+                let message = self.context.format_rfc3164(severity, Some(message));
+                self.$socket.send(message.to_string().as_bytes()).await?;
+                Ok(())
+            }
+
+            /// Send a message with the given severity as defined in RFC-5424.
+            pub async fn send_rfc5424<S: Into<String>, M: std::fmt::Display>(
+                &mut self,
+                severity: $crate::Severity,
+                msgid: Option<S>,
+                elements: Vec<$crate::SDElement>,
+                message: M,
+            ) -> std::io::Result<()> {
+                let message = self
+                    .context
+                    .format_rfc5424(severity, msgid, elements, Some(message));
+                self.$socket.send(message.to_string().as_bytes()).await?;
+                Ok(())
+            }
+
+            /// Send a pre-formatted message.
+            pub async fn send_formatted(&mut self, formatted: &[u8]) -> std::io::Result<()> {
+                self.$socket.send(formatted).await?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_async_datagram_syslog_sender;
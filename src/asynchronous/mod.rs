@@ -0,0 +1,222 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Async senders built on `tokio`, paralleling the blocking senders in [`crate::sender`].
+//!
+//! The formatting logic in [`crate::format::SyslogContext`] is shared with the blocking senders;
+//! only the I/O layer differs, using `tokio::net` and `tokio-rustls`/`tokio-native-tls`.
+
+use std::fmt;
+use std::io;
+
+use crate::SDElement;
+use crate::Severity;
+
+#[cfg(unix)]
+mod unix;
+#[cfg(unix)]
+pub use unix::*;
+
+#[cfg(feature = "native-tls")]
+mod native_tls_impl;
+#[cfg(feature = "native-tls")]
+pub use native_tls_impl::*;
+
+#[cfg(feature = "rustls")]
+mod rustls_impl;
+#[cfg(feature = "rustls")]
+pub use rustls_impl::*;
+
+mod tcp;
+pub use tcp::*;
+
+mod udp;
+pub use udp::*;
+
+pub(crate) mod internal;
+
+/// Static dispatch for the different async sender types.
+#[derive(Debug)]
+pub enum AsyncSyslogSender {
+    Tcp(AsyncTcpSender),
+    Udp(AsyncUdpSender),
+    #[cfg(feature = "native-tls")]
+    NativeTlsSender(AsyncNativeTlsSender),
+    #[cfg(feature = "rustls")]
+    RustlsSender(Box<AsyncRustlsSender>),
+    #[cfg(unix)]
+    UnixDatagram(AsyncUnixDatagramSender),
+    #[cfg(unix)]
+    UnixStream(AsyncUnixStreamSender),
+}
+
+impl AsyncSyslogSender {
+    /// Send a message with the given severity as defined in RFC-3164.
+    pub async fn send_rfc3164<M: fmt::Display>(
+        &mut self,
+        severity: Severity,
+        message: M,
+    ) -> io::Result<()> {
+        match self {
+            AsyncSyslogSender::Tcp(sender) => sender.send_rfc3164(severity, message).await,
+            AsyncSyslogSender::Udp(sender) => sender.send_rfc3164(severity, message).await,
+            #[cfg(feature = "native-tls")]
+            AsyncSyslogSender::NativeTlsSender(sender) => {
+                sender.send_rfc3164(severity, message).await
+            }
+            #[cfg(feature = "rustls")]
+            AsyncSyslogSender::RustlsSender(sender) => sender.send_rfc3164(severity, message).await,
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixDatagram(sender) => {
+                sender.send_rfc3164(severity, message).await
+            }
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixStream(sender) => sender.send_rfc3164(severity, message).await,
+        }
+    }
+
+    /// Send a message with the given severity as defined in RFC-5424.
+    pub async fn send_rfc5424<S: Into<String>, M: fmt::Display>(
+        &mut self,
+        severity: Severity,
+        msgid: Option<S>,
+        elements: Vec<SDElement>,
+        message: M,
+    ) -> io::Result<()> {
+        match self {
+            AsyncSyslogSender::Tcp(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+            AsyncSyslogSender::Udp(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+            #[cfg(feature = "native-tls")]
+            AsyncSyslogSender::NativeTlsSender(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+            #[cfg(feature = "rustls")]
+            AsyncSyslogSender::RustlsSender(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixDatagram(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixStream(sender) => {
+                sender.send_rfc5424(severity, msgid, elements, message).await
+            }
+        }
+    }
+
+    /// Send a pre-formatted message.
+    pub async fn send_formatted(&mut self, formatted: &[u8]) -> io::Result<()> {
+        match self {
+            AsyncSyslogSender::Tcp(sender) => sender.send_formatted(formatted).await,
+            AsyncSyslogSender::Udp(sender) => sender.send_formatted(formatted).await,
+            #[cfg(feature = "native-tls")]
+            AsyncSyslogSender::NativeTlsSender(sender) => sender.send_formatted(formatted).await,
+            #[cfg(feature = "rustls")]
+            AsyncSyslogSender::RustlsSender(sender) => sender.send_formatted(formatted).await,
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixDatagram(sender) => sender.send_formatted(formatted).await,
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixStream(sender) => sender.send_formatted(formatted).await,
+        }
+    }
+
+    /// Flush the underlying writer if needed.
+    ///
+    /// See [`crate::sender::SyslogSender::flush`] for why this matters for streaming writers.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        match self {
+            AsyncSyslogSender::Tcp(sender) => sender.flush().await,
+            AsyncSyslogSender::Udp(_) => Ok(()),
+            #[cfg(feature = "native-tls")]
+            AsyncSyslogSender::NativeTlsSender(sender) => sender.flush().await,
+            #[cfg(feature = "rustls")]
+            AsyncSyslogSender::RustlsSender(sender) => sender.flush().await,
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixDatagram(_) => Ok(()),
+            #[cfg(unix)]
+            AsyncSyslogSender::UnixStream(sender) => sender.flush().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::Severity;
+
+    fn accept_one(listener: TcpListener) -> std::thread::JoinHandle<Vec<u8>> {
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            stream.read_to_end(&mut received).unwrap();
+            received
+        })
+    }
+
+    #[tokio::test]
+    async fn tcp_sender_round_trips_over_loopback() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accept = accept_one(listener);
+
+        let mut sender = AsyncTcpSender::connect(addr).await.unwrap();
+        sender.send_rfc3164(Severity::Info, "hello").await.unwrap();
+        sender.flush().await.unwrap();
+        drop(sender);
+
+        let received = String::from_utf8(accept.join().unwrap()).unwrap();
+        assert!(received.contains("hello"));
+    }
+
+    #[tokio::test]
+    async fn tcp_sender_frames_successive_messages_so_they_are_separable() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accept = accept_one(listener);
+
+        let mut sender = AsyncTcpSender::connect(addr).await.unwrap();
+        sender.send_rfc3164(Severity::Info, "first").await.unwrap();
+        sender.send_rfc3164(Severity::Info, "second").await.unwrap();
+        sender.flush().await.unwrap();
+        drop(sender);
+
+        let received = String::from_utf8(accept.join().unwrap()).unwrap();
+        let mut messages = received.split('\n').filter(|line| !line.is_empty());
+        assert!(messages.next().unwrap().ends_with("first"));
+        assert!(messages.next().unwrap().ends_with("second"));
+        assert!(messages.next().is_none());
+    }
+
+    #[tokio::test]
+    async fn async_syslog_sender_dispatches_to_the_wrapped_tcp_sender() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accept = accept_one(listener);
+
+        let tcp = AsyncTcpSender::connect(addr).await.unwrap();
+        let mut sender = AsyncSyslogSender::Tcp(tcp);
+        sender.send_formatted(b"preformatted").await.unwrap();
+        drop(sender);
+
+        assert_eq!(accept.join().unwrap(), b"preformatted\n");
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub extern crate tokio_native_tls;
+
+use std::borrow::Cow;
+use std::io;
+
+use tokio::net::TcpStream;
+use tokio::net::ToSocketAddrs;
+use tokio_native_tls::native_tls;
+use tokio_native_tls::TlsConnector;
+use tokio_native_tls::TlsStream;
+
+use crate::asynchronous::internal::impl_async_stream_syslog_sender;
+use crate::format::SyslogContext;
+use crate::sender::Framing;
+
+/// Create an async TLS sender (backed by `native-tls`) that sends messages to the well-known
+/// port (6514).
+///
+/// See also [RFC-5425] ยง4.1 Port Assignment.
+///
+/// [RFC-5425]: https://datatracker.ietf.org/doc/html/rfc5425#section-4.1
+pub async fn native_tls_well_known<S: Into<String>>(domain: S) -> io::Result<AsyncNativeTlsSender> {
+    let domain = domain.into();
+    native_tls(format!("{domain}:6514"), domain).await
+}
+
+/// Create an async TLS sender (backed by `native-tls`) that sends messages to the given address.
+pub async fn native_tls<A: ToSocketAddrs, S: Into<String>>(
+    addr: A,
+    domain: S,
+) -> io::Result<AsyncNativeTlsSender> {
+    let connector = native_tls::TlsConnector::new().map_err(io::Error::other)?;
+    AsyncNativeTlsSender::connect(addr, domain, TlsConnector::from(connector)).await
+}
+
+/// An async syslog sender that sends messages to a TCP socket over TLS, backed by `native-tls`.
+#[derive(Debug)]
+pub struct AsyncNativeTlsSender {
+    stream: TlsStream<TcpStream>,
+    context: SyslogContext,
+    framing: Framing,
+}
+
+impl AsyncNativeTlsSender {
+    /// Connect to a TCP socket over TLS at the given address.
+    pub async fn connect<A: ToSocketAddrs, S: Into<String>>(
+        addr: A,
+        domain: S,
+        connector: TlsConnector,
+    ) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        let stream = connector
+            .connect(&domain.into(), stream)
+            .await
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            stream,
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\r\n")),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_stream_syslog_sender!(AsyncNativeTlsSender, stream);
@@ -0,0 +1,113 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub extern crate tokio_rustls;
+
+use std::borrow::Cow;
+use std::io;
+use std::sync::Arc;
+
+use tokio::net::TcpStream;
+use tokio::net::ToSocketAddrs;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::TlsConnector;
+
+use crate::asynchronous::internal::impl_async_stream_syslog_sender;
+use crate::format::SyslogContext;
+use crate::sender::Framing;
+
+/// Create an async TLS sender that sends messages to the well-known port (6514).
+///
+/// See also [RFC-5425] ยง4.1 Port Assignment.
+///
+/// [RFC-5425]: https://datatracker.ietf.org/doc/html/rfc5425#section-4.1
+pub async fn rustls_well_known<S: Into<String>>(domain: S) -> io::Result<AsyncRustlsSender> {
+    let domain = domain.into();
+    rustls(format!("{domain}:6514"), domain).await
+}
+
+/// Create an async TLS sender that sends messages to the given address.
+pub async fn rustls<A: ToSocketAddrs, S: Into<String>>(
+    addr: A,
+    domain: S,
+) -> io::Result<AsyncRustlsSender> {
+    // `load_native_certs` reads the platform's trust store from disk; run it on the blocking
+    // pool so it doesn't stall the async runtime worker driving this future.
+    let roots = tokio::task::spawn_blocking(|| {
+        let mut roots = RootCertStore::empty();
+        for cert in rustls_native_certs::load_native_certs().certs {
+            roots.add(cert).unwrap();
+        }
+        roots
+    })
+    .await
+    .map_err(io::Error::other)?;
+
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    rustls_with(addr, domain, Arc::new(config)).await
+}
+
+/// Create an async TLS sender that sends messages to the given address with certificate builder.
+pub async fn rustls_with<A: ToSocketAddrs, S: Into<String>>(
+    addr: A,
+    domain: S,
+    config: Arc<ClientConfig>,
+) -> io::Result<AsyncRustlsSender> {
+    AsyncRustlsSender::connect(addr, domain, config).await
+}
+
+/// An async syslog sender that sends messages to a TCP socket over TLS.
+#[derive(Debug)]
+pub struct AsyncRustlsSender {
+    stream: TlsStream<TcpStream>,
+    context: SyslogContext,
+    framing: Framing,
+}
+
+impl AsyncRustlsSender {
+    /// Connect to a TCP socket over TLS at the given address.
+    pub async fn connect<A: ToSocketAddrs, S: Into<String>>(
+        addr: A,
+        domain: S,
+        config: Arc<ClientConfig>,
+    ) -> io::Result<Self> {
+        let domain = ServerName::try_from(domain.into()).map_err(io::Error::other)?;
+        let stream = TcpStream::connect(addr).await?;
+        let connector = TlsConnector::from(config);
+        let stream = connector.connect(domain, stream).await?;
+        Ok(Self {
+            stream,
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\r\n")),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_stream_syslog_sender!(AsyncRustlsSender, stream);
@@ -0,0 +1,60 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::io;
+
+use tokio::net::TcpStream;
+use tokio::net::ToSocketAddrs;
+
+use crate::asynchronous::internal::impl_async_stream_syslog_sender;
+use crate::format::SyslogContext;
+use crate::sender::Framing;
+
+/// Create an async TCP sender that sends messages to the given address.
+pub async fn tcp<A: ToSocketAddrs>(addr: A) -> io::Result<AsyncTcpSender> {
+    AsyncTcpSender::connect(addr).await
+}
+
+/// An async syslog sender that sends messages to a TCP socket.
+#[derive(Debug)]
+pub struct AsyncTcpSender {
+    stream: TcpStream,
+    context: SyslogContext,
+    framing: Framing,
+}
+
+impl AsyncTcpSender {
+    /// Connect to a TCP socket at the given address.
+    pub async fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        Ok(Self {
+            stream,
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\n")),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_stream_syslog_sender!(AsyncTcpSender, stream);
@@ -0,0 +1,68 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+
+use tokio::net::ToSocketAddrs;
+use tokio::net::UdpSocket;
+
+use crate::asynchronous::internal::impl_async_datagram_syslog_sender;
+use crate::format::SyslogContext;
+
+/// Create an async UDP sender that sends messages to the well-known port (514).
+pub async fn udp_well_known() -> io::Result<AsyncUdpSender> {
+    udp("0.0.0.0:0", "127.0.0.1:514").await
+}
+
+/// Create an async UDP sender that sends messages to the given address.
+pub async fn udp<L: ToSocketAddrs, R: ToSocketAddrs>(
+    local: L,
+    remote: R,
+) -> io::Result<AsyncUdpSender> {
+    AsyncUdpSender::connect(local, remote).await
+}
+
+/// An async syslog sender that sends messages to a UDP socket.
+#[derive(Debug)]
+pub struct AsyncUdpSender {
+    socket: UdpSocket,
+    context: SyslogContext,
+}
+
+impl AsyncUdpSender {
+    /// Connect to a UDP socket at the given address.
+    pub async fn connect<L: ToSocketAddrs, R: ToSocketAddrs>(
+        local: L,
+        remote: R,
+    ) -> io::Result<Self> {
+        let socket = UdpSocket::bind(local).await?;
+        socket.connect(remote).await?;
+        Ok(Self {
+            socket,
+            context: SyslogContext::default(),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_datagram_syslog_sender!(AsyncUdpSender, socket);
@@ -0,0 +1,109 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::io;
+use std::path::Path;
+
+use tokio::net::UnixDatagram;
+use tokio::net::UnixStream;
+
+use crate::asynchronous::internal::impl_async_datagram_syslog_sender;
+use crate::asynchronous::internal::impl_async_stream_syslog_sender;
+use crate::format::SyslogContext;
+use crate::sender::Framing;
+
+/// Create an async sender that sends messages to the platform's default Unix syslog socket
+/// (`/dev/log` on Linux, `/var/run/syslog` on macOS) over a datagram socket.
+pub async fn unix_datagram_well_known() -> io::Result<AsyncUnixDatagramSender> {
+    #[cfg(target_os = "macos")]
+    const DEFAULT_PATH: &str = "/var/run/syslog";
+    #[cfg(not(target_os = "macos"))]
+    const DEFAULT_PATH: &str = "/dev/log";
+
+    unix_datagram(DEFAULT_PATH).await
+}
+
+/// Create an async sender that sends messages to the Unix datagram socket at the given path.
+pub async fn unix_datagram<P: AsRef<Path>>(path: P) -> io::Result<AsyncUnixDatagramSender> {
+    AsyncUnixDatagramSender::connect(path).await
+}
+
+/// Create an async sender that sends messages to the Unix stream socket at the given path.
+pub async fn unix_stream<P: AsRef<Path>>(path: P) -> io::Result<AsyncUnixStreamSender> {
+    AsyncUnixStreamSender::connect(path).await
+}
+
+/// An async syslog sender that sends messages to a Unix datagram socket.
+#[derive(Debug)]
+pub struct AsyncUnixDatagramSender {
+    socket: UnixDatagram,
+    context: SyslogContext,
+}
+
+impl AsyncUnixDatagramSender {
+    /// Connect to a Unix datagram socket at the given path.
+    pub async fn connect<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(path)?;
+        Ok(Self {
+            socket,
+            context: SyslogContext::default(),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_datagram_syslog_sender!(AsyncUnixDatagramSender, socket);
+
+/// An async syslog sender that sends messages to a Unix stream socket.
+#[derive(Debug)]
+pub struct AsyncUnixStreamSender {
+    stream: UnixStream,
+    context: SyslogContext,
+    framing: Framing,
+}
+
+impl AsyncUnixStreamSender {
+    /// Connect to a Unix stream socket at the given path.
+    pub async fn connect<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let stream = UnixStream::connect(path).await?;
+        Ok(Self {
+            stream,
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\n")),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+}
+
+impl_async_stream_syslog_sender!(AsyncUnixStreamSender, stream);
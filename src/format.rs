@@ -0,0 +1,311 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Render syslog messages as defined in [RFC-3164] and [RFC-5424].
+//!
+//! [`SyslogContext`] carries the header fields (facility, hostname, app name, process ID) that
+//! stay constant across a sender's messages; senders hold one and pass it to
+//! [`SyslogContext::format_rfc3164`]/[`SyslogContext::format_rfc5424`] for each message sent.
+//!
+//! [RFC-3164]: https://datatracker.ietf.org/doc/html/rfc3164
+//! [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424
+
+use std::fmt;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::SDElement;
+use crate::Severity;
+
+/// The facility a message is tagged with, per [RFC-5424] Table 1.
+///
+/// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Facility {
+    Kernel,
+    User,
+    Mail,
+    Daemon,
+    Auth,
+    Syslog,
+    Lpr,
+    News,
+    Uucp,
+    Cron,
+    AuthPriv,
+    Ftp,
+    Ntp,
+    Security,
+    Console,
+    SolarisCron,
+    Local0,
+    Local1,
+    Local2,
+    Local3,
+    Local4,
+    Local5,
+    Local6,
+    Local7,
+}
+
+impl Facility {
+    /// The numeric facility code, per [RFC-5424] Table 1.
+    ///
+    /// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.1
+    pub fn code(self) -> u8 {
+        match self {
+            Facility::Kernel => 0,
+            Facility::User => 1,
+            Facility::Mail => 2,
+            Facility::Daemon => 3,
+            Facility::Auth => 4,
+            Facility::Syslog => 5,
+            Facility::Lpr => 6,
+            Facility::News => 7,
+            Facility::Uucp => 8,
+            Facility::Cron => 9,
+            Facility::AuthPriv => 10,
+            Facility::Ftp => 11,
+            Facility::Ntp => 12,
+            Facility::Security => 13,
+            Facility::Console => 14,
+            Facility::SolarisCron => 15,
+            Facility::Local0 => 16,
+            Facility::Local1 => 17,
+            Facility::Local2 => 18,
+            Facility::Local3 => 19,
+            Facility::Local4 => 20,
+            Facility::Local5 => 21,
+            Facility::Local6 => 22,
+            Facility::Local7 => 23,
+        }
+    }
+}
+
+impl Default for Facility {
+    /// Defaults to `User` (1), the facility most senders without a more specific one should use.
+    fn default() -> Self {
+        Facility::User
+    }
+}
+
+/// The header fields of a syslog message that stay constant across a sender's messages.
+///
+/// Defaults to the `User` facility, a nil (`-`) hostname and app name, and this process's PID.
+#[derive(Debug, Clone)]
+pub struct SyslogContext {
+    facility: Facility,
+    hostname: Option<String>,
+    app_name: Option<String>,
+    proc_id: Option<String>,
+}
+
+impl Default for SyslogContext {
+    fn default() -> Self {
+        Self {
+            facility: Facility::default(),
+            hostname: None,
+            app_name: None,
+            proc_id: Some(std::process::id().to_string()),
+        }
+    }
+}
+
+impl SyslogContext {
+    /// Create a context with the default facility, nil hostname/app name, and this process's PID.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the facility messages are tagged with.
+    pub fn with_facility(mut self, facility: Facility) -> Self {
+        self.facility = facility;
+        self
+    }
+
+    /// Set the `HOSTNAME` field.
+    pub fn with_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Set the `APP-NAME` field.
+    pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = Some(app_name.into());
+        self
+    }
+
+    /// Set the `PROCID` field, which otherwise defaults to this process's PID.
+    pub fn with_proc_id(mut self, proc_id: impl Into<String>) -> Self {
+        self.proc_id = Some(proc_id.into());
+        self
+    }
+
+    fn pri(&self, severity: Severity) -> u8 {
+        self.facility.code() * 8 + severity.code()
+    }
+
+    /// Render `message` as an [RFC-3164] message with the given severity.
+    ///
+    /// [RFC-3164]: https://datatracker.ietf.org/doc/html/rfc3164#section-4.1
+    pub fn format_rfc3164<M: fmt::Display>(&self, severity: Severity, message: Option<M>) -> String {
+        let pri = self.pri(severity);
+        let timestamp = rfc3164_timestamp(SystemTime::now());
+        let hostname = self.hostname.as_deref().unwrap_or("-");
+        let tag = self.app_name.as_deref().unwrap_or("-");
+        match message {
+            Some(message) => format!("<{pri}>{timestamp} {hostname} {tag}: {message}"),
+            None => format!("<{pri}>{timestamp} {hostname} {tag}:"),
+        }
+    }
+
+    /// Render `message` as an [RFC-5424] message with the given severity, MSGID, and structured
+    /// data elements.
+    ///
+    /// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6
+    pub fn format_rfc5424<S: Into<String>, M: fmt::Display>(
+        &self,
+        severity: Severity,
+        msgid: Option<S>,
+        elements: Vec<SDElement>,
+        message: Option<M>,
+    ) -> String {
+        let pri = self.pri(severity);
+        let timestamp = rfc5424_timestamp(SystemTime::now());
+        let hostname = self.hostname.as_deref().unwrap_or("-");
+        let app_name = self.app_name.as_deref().unwrap_or("-");
+        let proc_id = self.proc_id.as_deref().unwrap_or("-");
+        let msgid = msgid.map(Into::into).unwrap_or_else(|| "-".to_string());
+        let structured_data = if elements.is_empty() {
+            "-".to_string()
+        } else {
+            elements.iter().map(SDElement::to_string).collect::<String>()
+        };
+        let header = format!(
+            "<{pri}>1 {timestamp} {hostname} {app_name} {proc_id} {msgid} {structured_data}"
+        );
+        match message {
+            Some(message) => format!("{header} {message}"),
+            None => header,
+        }
+    }
+}
+
+/// Split a UTC `SystemTime` into `(year, month, day, hour, minute, second, microsecond)`.
+fn unix_time_parts(now: SystemTime) -> (i64, u32, u32, u32, u32, u32, u32) {
+    let duration = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = duration.as_secs() as i64;
+    let micros = duration.subsec_micros();
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let hour = (secs_of_day / 3600) as u32;
+    let minute = ((secs_of_day % 3600) / 60) as u32;
+    let second = (secs_of_day % 60) as u32;
+    (year, month, day, hour, minute, second, micros)
+}
+
+/// Convert a day count since the Unix epoch to a proleptic-Gregorian `(year, month, day)`.
+///
+/// This is Howard Hinnant's [`civil_from_days`] algorithm, used here instead of a `time`/`chrono`
+/// dependency to keep this crate dependency-light.
+///
+/// [`civil_from_days`]: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
+
+/// Format a timestamp as `"Mmm dd hh:mm:ss"`, per [RFC-3164] ยง4.1.2.
+///
+/// [RFC-3164]: https://datatracker.ietf.org/doc/html/rfc3164#section-4.1.2
+fn rfc3164_timestamp(now: SystemTime) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let (_, month, day, hour, minute, second, _) = unix_time_parts(now);
+    format!(
+        "{} {:2} {:02}:{:02}:{:02}",
+        MONTHS[(month - 1) as usize],
+        day,
+        hour,
+        minute,
+        second
+    )
+}
+
+/// Format a timestamp as an RFC-3339 `TIMESTAMP`, per [RFC-5424] ยง6.2.3.
+///
+/// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.3
+fn rfc5424_timestamp(now: SystemTime) -> String {
+    let (year, month, day, hour, minute, second, micros) = unix_time_parts(now);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{micros:06}Z")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_from_days_matches_known_epoch_offsets() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        // 951868800 / 86400 = 11017; 2000-03-01T00:00:00Z is a well-known Unix-time fixture.
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+    }
+
+    #[test]
+    fn pri_combines_facility_and_severity_codes() {
+        let context = SyslogContext::new().with_facility(Facility::Local0);
+        assert_eq!(context.pri(Severity::Error), 16 * 8 + 3);
+    }
+
+    #[test]
+    fn rfc3164_defaults_hostname_and_tag_to_nil_value() {
+        let context = SyslogContext::new();
+        let formatted = context.format_rfc3164(Severity::Info, Some("hello"));
+        assert!(formatted.contains("- -: hello"), "{formatted}");
+    }
+
+    #[test]
+    fn rfc5424_uses_nil_value_for_absent_fields_and_renders_structured_data() {
+        let context = SyslogContext::new().with_proc_id("1234");
+        let element = SDElement::new("origin").add_param("ip", "127.0.0.1");
+        let formatted = context.format_rfc5424(
+            Severity::Notice,
+            Some("MSGID"),
+            vec![element],
+            Some("hello"),
+        );
+        assert!(formatted.starts_with("<13>1 "), "{formatted}");
+        assert!(formatted.contains(" - - 1234 MSGID [origin ip=\"127.0.0.1\"] hello"));
+    }
+
+    #[test]
+    fn rfc5424_uses_nil_value_for_empty_structured_data() {
+        let context = SyslogContext::new();
+        let formatted =
+            context.format_rfc5424::<&str, _>(Severity::Debug, None, Vec::new(), Some("hello"));
+        assert!(formatted.ends_with(" - - hello"), "{formatted}");
+    }
+}
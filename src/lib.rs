@@ -0,0 +1,33 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A fast syslog client, supporting RFC-3164 and RFC-5424 over UDP, TCP, TLS, and Unix sockets.
+
+pub mod format;
+mod sdelement;
+mod severity;
+
+pub use sdelement::SDElement;
+pub use severity::Severity;
+
+pub mod sender;
+
+/// Async senders built on `tokio`, paralleling [`sender`].
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+
+/// A [`log::Log`] implementation that forwards records to a [`sender::SyslogSender`].
+#[cfg(feature = "log")]
+#[path = "log_impl.rs"]
+pub mod log;
@@ -0,0 +1,194 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`log::Log`] implementation that forwards records to a [`SyslogSender`].
+//!
+//! Register it once at startup with [`log::set_boxed_logger`]:
+//!
+//! ```ignore
+//! let sender = fasyslog::sender::udp_well_known()?;
+//! let sender = fasyslog::sender::SyslogSender::Udp(sender);
+//! let logger = fasyslog::log::SyslogLogger::new(sender, fasyslog::log::SyslogFormat::Rfc5424);
+//! log::set_boxed_logger(Box::new(logger))?;
+//! log::set_max_level(log::LevelFilter::Info);
+//! ```
+
+use std::sync::Mutex;
+
+use log::Level;
+use log::LevelFilter;
+use log::Log;
+use log::Metadata;
+use log::Record;
+
+use crate::sender::SyslogSender;
+use crate::SDElement;
+use crate::Severity;
+
+/// Which syslog RFC a [`SyslogLogger`] should emit records as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogFormat {
+    /// Emit records as defined in RFC-3164.
+    Rfc3164,
+    /// Emit records as defined in RFC-5424, with the log target as MSGID and the module path,
+    /// file, and line carried as structured data.
+    Rfc5424,
+}
+
+/// Bridges the [`log`] facade to a [`SyslogSender`].
+///
+/// [`log::Level`] is translated to [`Severity`] as: `Error` → `Error`, `Warn` → `Warning`,
+/// `Info` → `Info`, `Debug` and `Trace` → `Debug`.
+pub struct SyslogLogger {
+    sender: Mutex<SyslogSender>,
+    format: SyslogFormat,
+}
+
+impl SyslogLogger {
+    /// Create a logger that emits records through `sender` in the given `format`.
+    pub fn new(sender: SyslogSender, format: SyslogFormat) -> Self {
+        Self {
+            sender: Mutex::new(sender),
+            format,
+        }
+    }
+}
+
+/// Translate a [`log::Level`] to the [`Severity`] it is emitted with.
+pub fn level_to_severity(level: Level) -> Severity {
+    match level {
+        Level::Error => Severity::Error,
+        Level::Warn => Severity::Warning,
+        Level::Info => Severity::Info,
+        Level::Debug => Severity::Debug,
+        Level::Trace => Severity::Debug,
+    }
+}
+
+impl Log for SyslogLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let Ok(mut sender) = self.sender.lock() else {
+            return;
+        };
+
+        let severity = level_to_severity(record.level());
+        let result = match self.format {
+            SyslogFormat::Rfc3164 => sender.send_rfc3164(severity, record.args()),
+            SyslogFormat::Rfc5424 => {
+                let msgid = Some(record.target().to_string());
+                let mut element = SDElement::new("origin");
+                if let Some(module_path) = record.module_path() {
+                    element = element.add_param("module_path", module_path);
+                }
+                if let Some(file) = record.file() {
+                    element = element.add_param("file", file);
+                }
+                if let Some(line) = record.line() {
+                    element = element.add_param("line", line.to_string());
+                }
+                sender.send_rfc5424(severity, msgid, vec![element], record.args())
+            }
+        };
+
+        if let Err(err) = result {
+            eprintln!("failed to send log record to syslog: {err}");
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut sender) = self.sender.lock() {
+            let _ = sender.flush();
+        }
+    }
+}
+
+/// Install `logger` as the global logger, defaulting the max level to `level`.
+pub fn init(logger: SyslogLogger, level: LevelFilter) -> Result<(), log::SetLoggerError> {
+    log::set_max_level(level);
+    log::set_boxed_logger(Box::new(logger))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::UdpSocket;
+
+    use log::Record;
+
+    use super::*;
+
+    #[test]
+    fn level_to_severity_maps_every_log_level() {
+        assert_eq!(level_to_severity(Level::Error), Severity::Error);
+        assert_eq!(level_to_severity(Level::Warn), Severity::Warning);
+        assert_eq!(level_to_severity(Level::Info), Severity::Info);
+        assert_eq!(level_to_severity(Level::Debug), Severity::Debug);
+        assert_eq!(level_to_severity(Level::Trace), Severity::Debug);
+    }
+
+    fn logger_over_loopback() -> (SyslogLogger, UdpSocket) {
+        // Most tests in this module log records directly via `SyslogLogger::log`, bypassing the
+        // `log` macros' own level check, so the global max level must be permissive enough for
+        // `enabled` (which consults it) to let those records through.
+        log::set_max_level(LevelFilter::Trace);
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let sender = crate::sender::udp("127.0.0.1:0", receiver.local_addr().unwrap()).unwrap();
+        let logger = SyslogLogger::new(SyslogSender::Udp(sender), SyslogFormat::Rfc5424);
+        (logger, receiver)
+    }
+
+    #[test]
+    fn rfc5424_log_carries_module_path_file_and_line_as_origin_sdelement() {
+        let (logger, receiver) = logger_over_loopback();
+        let record = Record::builder()
+            .level(Level::Info)
+            .target("my::target")
+            .module_path(Some("my::module"))
+            .file(Some("src/lib.rs"))
+            .line(Some(42))
+            .args(format_args!("hello"))
+            .build();
+
+        logger.log(&record);
+
+        let mut buf = [0u8; 1024];
+        let (n, _) = receiver.recv_from(&mut buf).unwrap();
+        let message = String::from_utf8(buf[..n].to_vec()).unwrap();
+        assert!(message.contains("origin"), "missing origin SDElement: {message}");
+        assert!(message.contains("my::module"), "missing module_path: {message}");
+        assert!(message.contains("src/lib.rs"), "missing file: {message}");
+        assert!(message.contains("42"), "missing line: {message}");
+        assert!(message.contains("my::target"), "missing MSGID: {message}");
+    }
+
+    #[test]
+    fn enabled_respects_the_configured_max_level() {
+        let (logger, _receiver) = logger_over_loopback();
+
+        log::set_max_level(LevelFilter::Info);
+        let metadata_at = |level| Metadata::builder().level(level).target("t").build();
+        assert!(logger.enabled(&metadata_at(Level::Error)));
+        assert!(logger.enabled(&metadata_at(Level::Info)));
+        assert!(!logger.enabled(&metadata_at(Level::Debug)));
+
+        log::set_max_level(LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// A structured data element of an RFC-5424 message, per [RFC-5424] ยง6.3.
+///
+/// Build one with [`SDElement::new`] and chain [`SDElement::add_param`] for each `PARAM-NAME`/
+/// `PARAM-VALUE` pair, then pass it to [`crate::sender::SyslogSender::send_rfc5424`].
+///
+/// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.3
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SDElement {
+    id: String,
+    params: Vec<(String, String)>,
+}
+
+impl SDElement {
+    /// Create an element with the given `SD-ID` and no parameters.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            params: Vec::new(),
+        }
+    }
+
+    /// Append a `PARAM-NAME`/`PARAM-VALUE` pair to this element.
+    pub fn add_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Escape `"`, `\`, and `]`, per [RFC-5424] ยง6.3.3's `PARAM-VALUE` grammar.
+///
+/// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.3.3
+fn escape_param_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl fmt::Display for SDElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.id)?;
+        for (name, value) in &self.params {
+            write!(f, " {}=\"{}\"", name, escape_param_value(value))?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_with_no_params_as_bare_id() {
+        assert_eq!(SDElement::new("origin").to_string(), "[origin]");
+    }
+
+    #[test]
+    fn displays_params_in_insertion_order() {
+        let element = SDElement::new("origin")
+            .add_param("ip", "127.0.0.1")
+            .add_param("software", "fasyslog");
+        assert_eq!(
+            element.to_string(),
+            "[origin ip=\"127.0.0.1\" software=\"fasyslog\"]"
+        );
+    }
+
+    #[test]
+    fn escapes_quotes_backslashes_and_closing_brackets_in_param_values() {
+        let element = SDElement::new("custom").add_param("path", r#"a"b\c]d"#);
+        assert_eq!(element.to_string(), r#"[custom path="a\"b\\c\]d"]"#);
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A non-blocking sender that hands messages off to a dedicated worker thread.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::sender::SyslogSender;
+use crate::SDElement;
+use crate::Severity;
+
+/// What a [`BackgroundSender`] does when its queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discard the oldest queued message to make room for the new one.
+    DropOldest,
+    /// Discard the new message, keeping the queue as-is.
+    DropNew,
+    /// Block the caller until space is available.
+    Block,
+}
+
+/// Configuration for a [`BackgroundSender`].
+#[derive(Debug, Clone)]
+pub struct BackgroundSenderConfig {
+    pub capacity: usize,
+    pub overflow_policy: OverflowPolicy,
+    pub flush_interval: Option<Duration>,
+    pub shutdown_timeout: Duration,
+}
+
+impl Default for BackgroundSenderConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            overflow_policy: OverflowPolicy::Block,
+            flush_interval: Some(Duration::from_secs(1)),
+            shutdown_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl BackgroundSenderConfig {
+    /// Create a config with the default queue depth (1024), blocking overflow policy, a 1s
+    /// periodic flush, and a 5s shutdown timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of queued messages.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Set what happens when the queue is full.
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Set how often the worker flushes the underlying sender absent new messages. `None`
+    /// disables periodic flushing.
+    pub fn with_flush_interval(mut self, flush_interval: Option<Duration>) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// Set how long [`BackgroundSender::shutdown`] (and `Drop`) waits for the worker to drain
+    /// the queue before giving up.
+    pub fn with_shutdown_timeout(mut self, shutdown_timeout: Duration) -> Self {
+        self.shutdown_timeout = shutdown_timeout;
+        self
+    }
+}
+
+enum Message {
+    Rfc3164(Severity, String),
+    Rfc5424(Severity, Option<String>, Vec<SDElement>, String),
+    Formatted(Vec<u8>),
+    Flush,
+    Shutdown,
+}
+
+struct Queue {
+    items: Mutex<VecDeque<Message>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    overflow_policy: OverflowPolicy,
+    closed: AtomicBool,
+}
+
+impl Queue {
+    fn new(capacity: usize, overflow_policy: OverflowPolicy) -> Self {
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity.min(256))),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity: capacity.max(1),
+            overflow_policy,
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    fn push(&self, message: Message) -> io::Result<()> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Self::shutdown_err());
+        }
+
+        let mut items = self.items.lock().unwrap();
+        // Re-check under the lock: `close()` may have run (and pushed its `Shutdown` sentinel)
+        // between the fast-path check above and acquiring the lock, or while this call was
+        // blocked in the `Block` wait loop below. Either way, inserting now would land the
+        // message after `Shutdown`, where the worker will never see it.
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Self::shutdown_err());
+        }
+        match self.overflow_policy {
+            OverflowPolicy::Block => {
+                while items.len() >= self.capacity && !self.closed.load(Ordering::Acquire) {
+                    items = self.not_full.wait(items).unwrap();
+                }
+                if self.closed.load(Ordering::Acquire) {
+                    return Err(Self::shutdown_err());
+                }
+                items.push_back(message);
+            }
+            OverflowPolicy::DropNew => {
+                if items.len() < self.capacity {
+                    items.push_back(message);
+                }
+            }
+            OverflowPolicy::DropOldest => {
+                if items.len() >= self.capacity {
+                    items.pop_front();
+                }
+                items.push_back(message);
+            }
+        }
+        drop(items);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn shutdown_err() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::NotConnected,
+            "background sender has already been shut down",
+        )
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        let mut items = self.items.lock().unwrap();
+        items.push_back(Message::Shutdown);
+        drop(items);
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Wait for the next message, or return `None` if `flush_interval` elapses first.
+    fn pop(&self, flush_interval: Option<Duration>) -> Option<Message> {
+        let mut items = self.items.lock().unwrap();
+        loop {
+            if let Some(message) = items.pop_front() {
+                self.not_full.notify_one();
+                return Some(message);
+            }
+            match flush_interval {
+                Some(interval) => {
+                    let (guard, timeout) = self.not_empty.wait_timeout(items, interval).unwrap();
+                    items = guard;
+                    if timeout.timed_out() && items.is_empty() {
+                        return None;
+                    }
+                }
+                None => items = self.not_empty.wait(items).unwrap(),
+            }
+        }
+    }
+}
+
+/// Wraps any [`SyslogSender`], running it on a dedicated worker thread so that `send_*` calls
+/// return immediately instead of blocking on a slow or stalled syslog server.
+///
+/// Dropping a `BackgroundSender` drains the queue and joins the worker thread, waiting up to
+/// the configured `shutdown_timeout`. Call [`BackgroundSender::shutdown`] directly to observe
+/// whether the drain completed in time.
+pub struct BackgroundSender {
+    queue: Arc<Queue>,
+    worker: Option<JoinHandle<()>>,
+    shutdown_timeout: Duration,
+}
+
+impl BackgroundSender {
+    /// Wrap `sender`, using the default [`BackgroundSenderConfig`].
+    pub fn new(sender: SyslogSender) -> Self {
+        Self::with_config(sender, BackgroundSenderConfig::default())
+    }
+
+    /// Wrap `sender`, running it on a dedicated worker thread configured by `config`.
+    pub fn with_config(sender: SyslogSender, config: BackgroundSenderConfig) -> Self {
+        let queue = Arc::new(Queue::new(config.capacity, config.overflow_policy));
+        let worker_queue = queue.clone();
+        let flush_interval = config.flush_interval;
+        let worker = thread::spawn(move || Self::run(sender, worker_queue, flush_interval));
+        Self {
+            queue,
+            worker: Some(worker),
+            shutdown_timeout: config.shutdown_timeout,
+        }
+    }
+
+    fn run(mut sender: SyslogSender, queue: Arc<Queue>, flush_interval: Option<Duration>) {
+        loop {
+            match queue.pop(flush_interval) {
+                Some(Message::Rfc3164(severity, message)) => {
+                    let _ = sender.send_rfc3164(severity, message);
+                }
+                Some(Message::Rfc5424(severity, msgid, elements, message)) => {
+                    let _ = sender.send_rfc5424(severity, msgid, elements, message);
+                }
+                Some(Message::Formatted(formatted)) => {
+                    let _ = sender.send_formatted(&formatted);
+                }
+                Some(Message::Flush) => {
+                    let _ = sender.flush();
+                }
+                Some(Message::Shutdown) => {
+                    let _ = sender.flush();
+                    return;
+                }
+                None => {
+                    let _ = sender.flush();
+                }
+            }
+        }
+    }
+
+    /// Queue a message with the given severity as defined in RFC-3164.
+    pub fn send_rfc3164<M: fmt::Display>(&self, severity: Severity, message: M) -> io::Result<()> {
+        self.queue.push(Message::Rfc3164(severity, message.to_string()))
+    }
+
+    /// Queue a message with the given severity as defined in RFC-5424.
+    pub fn send_rfc5424<S: Into<String>, M: fmt::Display>(
+        &self,
+        severity: Severity,
+        msgid: Option<S>,
+        elements: Vec<SDElement>,
+        message: M,
+    ) -> io::Result<()> {
+        self.queue.push(Message::Rfc5424(
+            severity,
+            msgid.map(Into::into),
+            elements,
+            message.to_string(),
+        ))
+    }
+
+    /// Queue a pre-formatted message.
+    pub fn send_formatted(&self, formatted: &[u8]) -> io::Result<()> {
+        self.queue.push(Message::Formatted(formatted.to_vec()))
+    }
+
+    /// Queue a flush of the underlying sender.
+    pub fn flush(&self) -> io::Result<()> {
+        self.queue.push(Message::Flush)
+    }
+
+    /// Close the queue, wait for the worker to drain it, and join its thread.
+    ///
+    /// Returns an error if the worker is still draining after `shutdown_timeout`; the worker
+    /// thread is left to finish in the background in that case.
+    pub fn shutdown(mut self) -> io::Result<()> {
+        self.shutdown_inner()
+    }
+
+    fn shutdown_inner(&mut self) -> io::Result<()> {
+        let Some(worker) = self.worker.take() else {
+            return Ok(());
+        };
+
+        self.queue.close();
+
+        let (done_tx, done_rx) = mpsc::channel();
+        let timeout = self.shutdown_timeout;
+        thread::spawn(move || {
+            let _ = worker.join();
+            let _ = done_tx.send(());
+        });
+
+        done_rx.recv_timeout(timeout).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::TimedOut,
+                "background sender did not shut down within the configured timeout",
+            )
+        })
+    }
+}
+
+impl Drop for BackgroundSender {
+    fn drop(&mut self) {
+        let _ = self.shutdown_inner();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(n: u8) -> Message {
+        Message::Formatted(vec![n])
+    }
+
+    fn front_byte(queue: &Queue) -> Option<u8> {
+        match queue.items.lock().unwrap().front() {
+            Some(Message::Formatted(bytes)) => bytes.first().copied(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn drop_new_discards_incoming_when_full() {
+        let queue = Queue::new(2, OverflowPolicy::DropNew);
+        queue.push(msg(1)).unwrap();
+        queue.push(msg(2)).unwrap();
+        queue.push(msg(3)).unwrap();
+        assert_eq!(queue.items.lock().unwrap().len(), 2);
+        assert_eq!(front_byte(&queue), Some(1));
+    }
+
+    #[test]
+    fn drop_oldest_evicts_front_to_make_room() {
+        let queue = Queue::new(2, OverflowPolicy::DropOldest);
+        queue.push(msg(1)).unwrap();
+        queue.push(msg(2)).unwrap();
+        queue.push(msg(3)).unwrap();
+        assert_eq!(queue.items.lock().unwrap().len(), 2);
+        assert_eq!(front_byte(&queue), Some(2));
+    }
+
+    #[test]
+    fn pop_returns_none_after_flush_interval_elapses_on_empty_queue() {
+        let queue = Queue::new(4, OverflowPolicy::Block);
+        let start = std::time::Instant::now();
+        assert!(queue.pop(Some(Duration::from_millis(20))).is_none());
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn close_drains_queued_messages_before_the_shutdown_sentinel() {
+        let queue = Queue::new(4, OverflowPolicy::Block);
+        queue.push(msg(1)).unwrap();
+        queue.close();
+        assert!(matches!(queue.pop(None), Some(Message::Formatted(_))));
+        assert!(matches!(queue.pop(None), Some(Message::Shutdown)));
+    }
+
+    #[test]
+    fn push_after_close_is_rejected_not_silently_dropped() {
+        let queue = Queue::new(4, OverflowPolicy::Block);
+        queue.close();
+        let err = queue.push(msg(1)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotConnected);
+    }
+
+    #[test]
+    fn blocked_push_is_rejected_rather_than_landing_after_shutdown() {
+        let queue = Arc::new(Queue::new(1, OverflowPolicy::Block));
+        queue.push(msg(1)).unwrap();
+
+        let blocked = queue.clone();
+        let handle = thread::spawn(move || blocked.push(msg(2)));
+        thread::sleep(Duration::from_millis(50));
+        queue.close();
+
+        assert!(handle.join().unwrap().is_err());
+        // The Shutdown sentinel must still be the last thing a drain sees.
+        assert!(matches!(queue.pop(None), Some(Message::Formatted(_))));
+        assert!(matches!(queue.pop(None), Some(Message::Shutdown)));
+    }
+}
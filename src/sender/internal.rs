@@ -12,6 +12,228 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+use std::io;
+use std::net::SocketAddr;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::sender::socks5;
+use crate::sender::socks5::Socks5Credentials;
+use crate::sender::socks5::Socks5Target;
+
+/// How a stream sender delimits successive messages on the wire, per [RFC-6587].
+///
+/// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Framing {
+    /// Non-transparent framing (ยง3.4.2): append `postfix` (typically `"\n"` or `"\r\n"`) after
+    /// each message.
+    NonTransparent(Cow<'static, str>),
+    /// Octet-counting framing (ยง3.4.1): prefix each message with its length in bytes, as an
+    /// ASCII decimal number followed by a single space, and emit no trailing delimiter.
+    OctetCounting,
+}
+
+impl Framing {
+    /// Frame `message` according to this framing mode.
+    pub(crate) fn frame(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Framing::NonTransparent(postfix) => {
+                let mut framed = Vec::with_capacity(message.len() + postfix.len());
+                framed.extend_from_slice(message);
+                framed.extend_from_slice(postfix.as_bytes());
+                framed
+            }
+            Framing::OctetCounting => {
+                let mut framed = format!("{} ", message.len()).into_bytes();
+                framed.extend_from_slice(message);
+                framed
+            }
+        }
+    }
+}
+
+/// Re-resolves the address(es) a reconnecting stream sender dials, so that each reconnect
+/// attempt picks up DNS changes instead of redialing a frozen, possibly stale address.
+///
+/// Only built when a sender's `set_reconnect_policy` is called; the plain `connect` path
+/// resolves the address once and never needs to carry it (or its bounds) around.
+pub(crate) type AddrResolver = Arc<dyn Fn() -> io::Result<Vec<SocketAddr>> + Send + Sync>;
+
+pub(crate) fn resolver<A: ToSocketAddrs + Clone + Send + Sync + 'static>(addr: A) -> AddrResolver {
+    Arc::new(move || Ok(addr.clone().to_socket_addrs()?.collect()))
+}
+
+/// How a reconnecting stream sender redials its destination, re-resolving the address each
+/// time per [`AddrResolver`].
+#[derive(Clone)]
+pub(crate) enum ConnectSpec {
+    Direct(AddrResolver),
+    Proxy(AddrResolver, Socks5Target, Option<Socks5Credentials>),
+}
+
+impl ConnectSpec {
+    pub(crate) fn dial(&self) -> io::Result<TcpStream> {
+        match self {
+            ConnectSpec::Direct(resolve) => {
+                let addrs = resolve()?;
+                TcpStream::connect(addrs.as_slice())
+            }
+            ConnectSpec::Proxy(resolve, target, credentials) => {
+                let proxy_addrs = resolve()?;
+                socks5::connect(proxy_addrs.as_slice(), target.clone(), credentials.as_ref())
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ConnectSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectSpec::Direct(_) => f.debug_tuple("Direct").finish(),
+            ConnectSpec::Proxy(_, target, credentials) => f
+                .debug_tuple("Proxy")
+                .field(target)
+                .field(&credentials.as_ref().map(|_| "<redacted>"))
+                .finish(),
+        }
+    }
+}
+
+/// Controls how a streaming sender recovers from a dropped connection.
+///
+/// When attached to a sender via its `set_reconnect_policy` method, a write or flush that fails
+/// with a connection-level error (e.g. `BrokenPipe`, `ConnectionReset`) triggers a fresh call to
+/// the sender's original `connect` logic instead of propagating the error immediately. Attempts
+/// are retried with exponential backoff, capped at `max_backoff`, until `max_attempts` is reached.
+///
+/// Known limitation: messages at or above the stream's `BufWriter` capacity (8KB) bypass that
+/// buffer and are written directly to the socket. If such a write fails partway through, the
+/// retried write after reconnecting resends the whole message, which can duplicate the bytes
+/// that already reached the old connection. Messages below the buffer's capacity are unaffected,
+/// since they are only ever flushed to the socket as a whole.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub backoff_multiplier: f64,
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            backoff_multiplier: 2.0,
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Create a policy with the default backoff schedule (100ms initial, 30s cap, 5 attempts).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the backoff applied before the second reconnect attempt; the first attempt is made
+    /// immediately.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the upper bound the backoff is capped at.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the multiplier applied to the backoff after each failed attempt.
+    pub fn with_backoff_multiplier(mut self, backoff_multiplier: f64) -> Self {
+        self.backoff_multiplier = backoff_multiplier;
+        self
+    }
+
+    /// Set the maximum number of reconnect attempts before giving up.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+}
+
+/// Re-runs a sender's `connect` logic to recover a broken stream, per a [`ReconnectPolicy`].
+pub(crate) struct Reconnector<S> {
+    policy: ReconnectPolicy,
+    connect: Box<dyn FnMut() -> io::Result<S> + Send>,
+}
+
+impl<S> Reconnector<S> {
+    pub(crate) fn new(
+        policy: ReconnectPolicy,
+        connect: impl FnMut() -> io::Result<S> + Send + 'static,
+    ) -> Self {
+        Self {
+            policy,
+            connect: Box::new(connect),
+        }
+    }
+
+    /// Attempt to reconnect, retrying with exponential backoff until the policy's
+    /// `max_attempts` is exhausted. Returns the last error on failure.
+    pub(crate) fn reconnect(&mut self) -> io::Result<S> {
+        // `Duration::mul_f64` panics on a negative, infinite, or NaN multiplier; `ReconnectPolicy`
+        // is a public, unchecked struct, so clamp here rather than trust the caller.
+        let multiplier = self.policy.backoff_multiplier;
+        let multiplier = if multiplier.is_finite() {
+            multiplier.max(0.0)
+        } else {
+            0.0
+        };
+
+        let attempts = self.policy.max_attempts.max(1);
+        let mut backoff = self.policy.initial_backoff;
+        let mut last_err = None;
+        for attempt in 0..attempts {
+            if attempt > 0 {
+                thread::sleep(backoff);
+                backoff = backoff.mul_f64(multiplier).min(self.policy.max_backoff);
+            }
+            match (self.connect)() {
+                Ok(stream) => return Ok(stream),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| io::Error::other("reconnect attempted with no tries")))
+    }
+}
+
+impl<S> std::fmt::Debug for Reconnector<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reconnector")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether an I/O error indicates the underlying connection was lost, as opposed to some other
+/// failure (e.g. formatting or permission errors) that a reconnect would not fix.
+pub(crate) fn is_connection_error(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::UnexpectedEof
+    )
+}
+
 macro_rules! impl_stream_syslog_sender {
     ($sender:ident, $stream:ident) => {
         impl $sender {
@@ -21,9 +243,9 @@ macro_rules! impl_stream_syslog_sender {
                 severity: $crate::Severity,
                 message: M,
             ) -> std::io::Result<()> {
-                use std::io::Write;
                 let message = self.context.format_rfc3164(severity, Some(message));
-                write!(&mut self.$stream, "{message}")
+                let framed = self.framing.frame(message.to_string().as_bytes());
+                self.write_with_reconnect(&framed)
             }
 
             /// Send a message with the given severity as defined in RFC-5424.
@@ -34,17 +256,68 @@ macro_rules! impl_stream_syslog_sender {
                 elements: Vec<$crate::SDElement>,
                 message: M,
             ) -> std::io::Result<()> {
-                use std::io::Write;
                 let message = self
                     .context
                     .format_rfc5424(severity, msgid, elements, Some(message));
-                write!(&mut self.$stream, "{message}")
+                let framed = self.framing.frame(message.to_string().as_bytes());
+                self.write_with_reconnect(&framed)
             }
 
             /// Flush the writer.
-            pub fn flush(&mut self) -> io::Result<()> {
+            pub fn flush(&mut self) -> std::io::Result<()> {
                 use std::io::Write;
-                self.writer.flush()
+                match self.$stream.flush() {
+                    Ok(()) => Ok(()),
+                    Err(err)
+                        if self.reconnect.is_some()
+                            && $crate::sender::internal::is_connection_error(&err) =>
+                    {
+                        self.reestablish_stream()?;
+                        self.$stream.flush()
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+
+            /// Write `bytes` to the stream, transparently reconnecting once per the configured
+            /// [`$crate::sender::internal::ReconnectPolicy`] when the write fails with a
+            /// connection-level error.
+            ///
+            /// See [`$crate::sender::internal::ReconnectPolicy`]'s known limitation: retrying
+            /// `bytes` in full after reconnecting can duplicate a partial direct write that
+            /// already reached the old connection, for messages at or above the buffer capacity.
+            fn write_with_reconnect(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+                use std::io::Write;
+                match self.$stream.write_all(bytes) {
+                    Ok(()) => Ok(()),
+                    Err(err)
+                        if self.reconnect.is_some()
+                            && $crate::sender::internal::is_connection_error(&err) =>
+                    {
+                        self.reestablish_stream()?;
+                        self.$stream.write_all(bytes)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+
+            fn reestablish_stream(&mut self) -> std::io::Result<()> {
+                use std::io::Write;
+                // Anything still sitting in the old `BufWriter`'s buffer never reached the
+                // broken socket; carry it over to the new stream instead of silently dropping
+                // it, so a reconnect resends rather than loses in-flight messages.
+                let pending = self.$stream.buffer().to_vec();
+                let stream = self
+                    .reconnect
+                    .as_mut()
+                    .expect("reconnect is Some, checked by caller")
+                    .reconnect()?;
+                let mut writer = std::io::BufWriter::new(stream);
+                if !pending.is_empty() {
+                    writer.write_all(&pending)?;
+                }
+                self.$stream = writer;
+                Ok(())
             }
         }
     };
@@ -85,3 +358,51 @@ macro_rules! impl_datagram_syslog_sender {
 }
 
 pub(crate) use impl_datagram_syslog_sender;
+
+macro_rules! impl_syslog_stream_send_formatted {
+    ($sender:ident, $stream:ident) => {
+        impl $sender {
+            /// Send a pre-formatted message.
+            pub fn send_formatted(&mut self, formatted: &[u8]) -> std::io::Result<()> {
+                use std::io::Write;
+                let framed = self.framing.frame(formatted);
+                match self.$stream.write_all(&framed) {
+                    Ok(()) => Ok(()),
+                    Err(err)
+                        if self.reconnect.is_some()
+                            && $crate::sender::internal::is_connection_error(&err) =>
+                    {
+                        self.reestablish_stream()?;
+                        self.$stream.write_all(&framed)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_syslog_stream_send_formatted;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_transparent_framing_appends_postfix() {
+        let framing = Framing::NonTransparent(Cow::Borrowed("\r\n"));
+        assert_eq!(framing.frame(b"hello"), b"hello\r\n");
+    }
+
+    #[test]
+    fn octet_counting_framing_prefixes_byte_length() {
+        let framed = Framing::OctetCounting.frame(b"hello");
+        assert_eq!(framed, b"5 hello");
+    }
+
+    #[test]
+    fn octet_counting_framing_counts_bytes_not_chars() {
+        let framed = Framing::OctetCounting.frame("héllo".as_bytes());
+        assert_eq!(framed, "6 héllo".as_bytes());
+    }
+}
@@ -38,10 +38,21 @@ pub use rustls_impl::*;
 mod tcp_impl;
 pub use tcp_impl::*;
 
-mod udp_impl;
-pub use udp_impl::*;
+mod udp;
+pub use udp::*;
+
+mod socks5;
+pub use socks5::Socks5Credentials;
+pub use socks5::Socks5Target;
+
+mod background;
+pub use background::BackgroundSender;
+pub use background::BackgroundSenderConfig;
+pub use background::OverflowPolicy;
 
 pub(crate) mod internal;
+pub use internal::Framing;
+pub use internal::ReconnectPolicy;
 
 /// Static dispatch for the different sender types.
 #[derive(Debug)]
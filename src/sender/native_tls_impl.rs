@@ -0,0 +1,203 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub extern crate native_tls;
+
+use std::borrow::Cow;
+use std::io;
+use std::io::BufWriter;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+
+use native_tls::TlsConnector;
+use native_tls::TlsStream;
+
+use crate::format::SyslogContext;
+use crate::sender::internal::impl_stream_syslog_sender;
+use crate::sender::internal::impl_syslog_stream_send_formatted;
+use crate::sender::internal::resolver;
+use crate::sender::internal::ConnectSpec;
+use crate::sender::internal::Framing;
+use crate::sender::internal::ReconnectPolicy;
+use crate::sender::internal::Reconnector;
+use crate::sender::socks5;
+use crate::sender::socks5::Socks5Credentials;
+use crate::sender::socks5::Socks5Target;
+
+type NativeTlsStream = TlsStream<TcpStream>;
+
+/// Create a TLS sender (backed by `native-tls`) that sends messages to the well-known port (6514).
+///
+/// See also [RFC-5425] ยง4.1 Port Assignment.
+///
+/// [RFC-5425]: https://datatracker.ietf.org/doc/html/rfc5425#section-4.1
+pub fn native_tls_well_known<S: Into<String>>(domain: S) -> io::Result<NativeTlsSender> {
+    let domain = domain.into();
+    native_tls(format!("{domain}:6514"), domain)
+}
+
+/// Create a TLS sender (backed by `native-tls`) that sends messages to the given address.
+pub fn native_tls<A: ToSocketAddrs, S: Into<String>>(
+    addr: A,
+    domain: S,
+) -> io::Result<NativeTlsSender> {
+    let connector = TlsConnector::new().map_err(io::Error::other)?;
+    NativeTlsSender::connect(addr, domain, connector)
+}
+
+/// A syslog sender that sends messages to a TCP socket over TLS, backed by `native-tls`.
+///
+/// Users can obtain a `NativeTlsSender` by calling [`native_tls_well_known()`] or [`native_tls()`].
+#[derive(Debug)]
+pub struct NativeTlsSender {
+    writer: BufWriter<NativeTlsStream>,
+    context: SyslogContext,
+    framing: Framing,
+    domain: String,
+    connector: TlsConnector,
+    reconnect: Option<Reconnector<NativeTlsStream>>,
+}
+
+impl NativeTlsSender {
+    /// Connect to a TCP socket over TLS at the given address.
+    pub fn connect<A: ToSocketAddrs, S: Into<String>>(
+        addr: A,
+        domain: S,
+        connector: TlsConnector,
+    ) -> io::Result<Self> {
+        let addrs: Vec<_> = addr.to_socket_addrs()?.collect();
+        let stream = TcpStream::connect(addrs.as_slice())?;
+        Self::from_stream(stream, domain.into(), connector)
+    }
+
+    /// Connect to a TCP socket over TLS at the given address through a [SOCKS5] proxy.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn native_tls_via_proxy<P: ToSocketAddrs, T: Into<Socks5Target>, S: Into<String>>(
+        proxy_addr: P,
+        target: T,
+        domain: S,
+        connector: TlsConnector,
+    ) -> io::Result<Self> {
+        Self::native_tls_via_proxy_with_auth(proxy_addr, target, domain, connector, None)
+    }
+
+    /// Connect over TLS through a [SOCKS5] proxy that requires username/password authentication.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn native_tls_via_proxy_with_auth<P: ToSocketAddrs, T: Into<Socks5Target>, S: Into<String>>(
+        proxy_addr: P,
+        target: T,
+        domain: S,
+        connector: TlsConnector,
+        credentials: Option<Socks5Credentials>,
+    ) -> io::Result<Self> {
+        let proxy_addrs: Vec<_> = proxy_addr.to_socket_addrs()?.collect();
+        let target = target.into();
+        let stream = socks5::connect(proxy_addrs.as_slice(), target.clone(), credentials.as_ref())?;
+        Self::from_stream(stream, domain.into(), connector)
+    }
+
+    fn from_stream(stream: TcpStream, domain: String, connector: TlsConnector) -> io::Result<Self> {
+        let stream = Self::wrap_tls(stream, &domain, &connector)?;
+        Ok(Self {
+            writer: BufWriter::new(stream),
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\r\n")),
+            domain,
+            connector,
+            reconnect: None,
+        })
+    }
+
+    fn wrap_tls(
+        stream: TcpStream,
+        domain: &str,
+        connector: &TlsConnector,
+    ) -> io::Result<NativeTlsStream> {
+        connector.connect(domain, stream).map_err(io::Error::other)
+    }
+
+    /// Set the postfix appended to each message under non-transparent framing.
+    ///
+    /// This is generally '\r\n' as defined in [RFC-6587] ยง3.4.2.
+    ///
+    /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+    pub fn set_postfix(&mut self, postfix: impl Into<Cow<'static, str>>) {
+        self.framing = Framing::NonTransparent(postfix.into());
+    }
+
+    /// Set how successive messages are delimited on the wire, per [RFC-6587].
+    ///
+    /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+    pub fn set_framing(&mut self, framing: Framing) {
+        self.framing = framing;
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error.
+    ///
+    /// Reconnecting redials `addr`, re-resolving it on every attempt so reconnects pick up DNS
+    /// changes instead of redialing a frozen, possibly stale address, and redoes the TLS
+    /// handshake for the domain this sender was originally constructed with. `addr` need not be
+    /// the same value passed to [`NativeTlsSender::connect`], but should resolve to the same
+    /// destination.
+    pub fn set_reconnect_policy<A: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        addr: A,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Direct(resolver(addr));
+        let domain = self.domain.clone();
+        let connector = self.connector.clone();
+        self.reconnect = Some(Reconnector::new(policy, move || {
+            let stream = connect_spec.dial()?;
+            Self::wrap_tls(stream, &domain, &connector)
+        }));
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error, for a
+    /// sender constructed via [`NativeTlsSender::native_tls_via_proxy`] or
+    /// [`NativeTlsSender::native_tls_via_proxy_with_auth`].
+    ///
+    /// Reconnecting redials `proxy_addr`, redoes the SOCKS5 CONNECT to `target`, and redoes the
+    /// TLS handshake for the domain this sender was originally constructed with.
+    pub fn set_reconnect_policy_via_proxy<P: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        proxy_addr: P,
+        target: Socks5Target,
+        credentials: Option<Socks5Credentials>,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Proxy(resolver(proxy_addr), target, credentials);
+        let domain = self.domain.clone();
+        let connector = self.connector.clone();
+        self.reconnect = Some(Reconnector::new(policy, move || {
+            let stream = connect_spec.dial()?;
+            Self::wrap_tls(stream, &domain, &connector)
+        }));
+    }
+}
+
+impl_stream_syslog_sender!(NativeTlsSender, writer);
+impl_syslog_stream_send_formatted!(NativeTlsSender, writer);
@@ -28,8 +28,18 @@ use rustls::RootCertStore;
 use rustls::StreamOwned;
 
 use crate::format::SyslogContext;
-use crate::sender::internal::impl_syslog_sender_common;
+use crate::sender::internal::impl_stream_syslog_sender;
 use crate::sender::internal::impl_syslog_stream_send_formatted;
+use crate::sender::internal::resolver;
+use crate::sender::internal::ConnectSpec;
+use crate::sender::internal::Framing;
+use crate::sender::internal::ReconnectPolicy;
+use crate::sender::internal::Reconnector;
+use crate::sender::socks5;
+use crate::sender::socks5::Socks5Credentials;
+use crate::sender::socks5::Socks5Target;
+
+type RustlsStream = StreamOwned<ClientConnection, TcpStream>;
 
 /// Create a TLS sender that sends messages to the well-known port (6514).
 ///
@@ -70,9 +80,12 @@ pub fn rustls_with<A: ToSocketAddrs, S: Into<String>>(
 /// or [`rustls_with()`].
 #[derive(Debug)]
 pub struct RustlsSender {
-    writer: BufWriter<StreamOwned<ClientConnection, TcpStream>>,
+    writer: BufWriter<RustlsStream>,
     context: SyslogContext,
-    postfix: Cow<'static, str>,
+    framing: Framing,
+    domain: String,
+    config: Arc<ClientConfig>,
+    reconnect: Option<Reconnector<RustlsStream>>,
 }
 
 impl RustlsSender {
@@ -82,25 +95,75 @@ impl RustlsSender {
         domain: S,
         config: Arc<ClientConfig>,
     ) -> io::Result<Self> {
-        let domain = domain.into();
-        let domain = ServerName::try_from(domain).map_err(io::Error::other)?;
-        let stream = TcpStream::connect(addr)?;
-        let conn = ClientConnection::new(config, domain).map_err(io::Error::other)?;
-        let stream = StreamOwned::new(conn, stream);
+        let addrs: Vec<_> = addr.to_socket_addrs()?.collect();
+        let stream = TcpStream::connect(addrs.as_slice())?;
+        Self::from_stream(stream, domain.into(), config)
+    }
+
+    /// Connect to a TCP socket over TLS at the given address through a [SOCKS5] proxy.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn rustls_via_proxy<P: ToSocketAddrs, T: Into<Socks5Target>, S: Into<String>>(
+        proxy_addr: P,
+        target: T,
+        domain: S,
+        config: Arc<ClientConfig>,
+    ) -> io::Result<Self> {
+        Self::rustls_via_proxy_with_auth(proxy_addr, target, domain, config, None)
+    }
+
+    /// Connect over TLS through a [SOCKS5] proxy that requires username/password authentication.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn rustls_via_proxy_with_auth<P: ToSocketAddrs, T: Into<Socks5Target>, S: Into<String>>(
+        proxy_addr: P,
+        target: T,
+        domain: S,
+        config: Arc<ClientConfig>,
+        credentials: Option<Socks5Credentials>,
+    ) -> io::Result<Self> {
+        let proxy_addrs: Vec<_> = proxy_addr.to_socket_addrs()?.collect();
+        let target = target.into();
+        let stream = socks5::connect(proxy_addrs.as_slice(), target.clone(), credentials.as_ref())?;
+        Self::from_stream(stream, domain.into(), config)
+    }
+
+    fn from_stream(stream: TcpStream, domain: String, config: Arc<ClientConfig>) -> io::Result<Self> {
+        let stream = Self::wrap_tls(stream, &domain, config.clone())?;
         Ok(Self {
             writer: BufWriter::new(stream),
             context: SyslogContext::default(),
-            postfix: Cow::Borrowed("\r\n"),
+            framing: Framing::NonTransparent(Cow::Borrowed("\r\n")),
+            domain,
+            config,
+            reconnect: None,
         })
     }
 
-    /// Set the postfix when formatting Syslog message.
+    fn wrap_tls(
+        stream: TcpStream,
+        domain: &str,
+        config: Arc<ClientConfig>,
+    ) -> io::Result<RustlsStream> {
+        let server_name = ServerName::try_from(domain.to_string()).map_err(io::Error::other)?;
+        let conn = ClientConnection::new(config, server_name).map_err(io::Error::other)?;
+        Ok(StreamOwned::new(conn, stream))
+    }
+
+    /// Set the postfix appended to each message under non-transparent framing.
     ///
     /// This is generally '\r\n' as defined in [RFC-6587] ยง3.4.2.
     ///
     /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
     pub fn set_postfix(&mut self, postfix: impl Into<Cow<'static, str>>) {
-        self.postfix = postfix.into();
+        self.framing = Framing::NonTransparent(postfix.into());
+    }
+
+    /// Set how successive messages are delimited on the wire, per [RFC-6587].
+    ///
+    /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+    pub fn set_framing(&mut self, framing: Framing) {
+        self.framing = framing;
     }
 
     /// Set the context when formatting Syslog message.
@@ -112,7 +175,50 @@ impl RustlsSender {
     pub fn mut_context(&mut self) -> &mut SyslogContext {
         &mut self.context
     }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error.
+    ///
+    /// Reconnecting redials `addr`, re-resolving it on every attempt so reconnects pick up DNS
+    /// changes instead of redialing a frozen, possibly stale address, and redoes the TLS
+    /// handshake for the domain this sender was originally constructed with. `addr` need not be
+    /// the same value passed to [`RustlsSender::connect`], but should resolve to the same
+    /// destination.
+    pub fn set_reconnect_policy<A: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        addr: A,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Direct(resolver(addr));
+        let domain = self.domain.clone();
+        let config = self.config.clone();
+        self.reconnect = Some(Reconnector::new(policy, move || {
+            let stream = connect_spec.dial()?;
+            Self::wrap_tls(stream, &domain, config.clone())
+        }));
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error, for a
+    /// sender constructed via [`RustlsSender::rustls_via_proxy`] or
+    /// [`RustlsSender::rustls_via_proxy_with_auth`].
+    ///
+    /// Reconnecting redials `proxy_addr`, redoes the SOCKS5 CONNECT to `target`, and redoes the
+    /// TLS handshake for the domain this sender was originally constructed with.
+    pub fn set_reconnect_policy_via_proxy<P: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        proxy_addr: P,
+        target: Socks5Target,
+        credentials: Option<Socks5Credentials>,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Proxy(resolver(proxy_addr), target, credentials);
+        let domain = self.domain.clone();
+        let config = self.config.clone();
+        self.reconnect = Some(Reconnector::new(policy, move || {
+            let stream = connect_spec.dial()?;
+            Self::wrap_tls(stream, &domain, config.clone())
+        }));
+    }
 }
 
-impl_syslog_sender_common!(RustlsSender);
-impl_syslog_stream_send_formatted!(RustlsSender);
+impl_stream_syslog_sender!(RustlsSender, writer);
+impl_syslog_stream_send_formatted!(RustlsSender, writer);
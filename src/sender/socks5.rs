@@ -0,0 +1,355 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal [SOCKS5] client handshake used to reach a syslog collector through a proxy.
+//!
+//! [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+
+const SOCKS5_VERSION: u8 = 0x05;
+const AUTH_NONE: u8 = 0x00;
+const AUTH_USERNAME_PASSWORD: u8 = 0x02;
+const ATYP_IPV4: u8 = 0x01;
+const ATYP_DOMAIN: u8 = 0x03;
+const ATYP_IPV6: u8 = 0x04;
+const CMD_CONNECT: u8 = 0x01;
+const REPLY_SUCCEEDED: u8 = 0x00;
+
+/// The destination address of a [SOCKS5] `CONNECT` request.
+///
+/// A domain name is kept unresolved so that it can be forwarded as-is with `ATYP` `0x03`,
+/// letting the proxy perform the DNS resolution on our behalf.
+///
+/// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+#[derive(Debug, Clone)]
+pub enum Socks5Target {
+    Addr(SocketAddr),
+    Domain(String, u16),
+}
+
+impl From<SocketAddr> for Socks5Target {
+    fn from(addr: SocketAddr) -> Self {
+        Socks5Target::Addr(addr)
+    }
+}
+
+impl From<(String, u16)> for Socks5Target {
+    fn from((domain, port): (String, u16)) -> Self {
+        Socks5Target::Domain(domain, port)
+    }
+}
+
+impl From<(&str, u16)> for Socks5Target {
+    fn from((domain, port): (&str, u16)) -> Self {
+        Socks5Target::Domain(domain.to_string(), port)
+    }
+}
+
+/// Username/password credentials for [SOCKS5] authentication method `0x02`.
+///
+/// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+#[derive(Debug, Clone)]
+pub struct Socks5Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl Socks5Credentials {
+    /// Create new username/password credentials.
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+/// Connect to `target` through a [SOCKS5] proxy listening at `proxy_addr`.
+///
+/// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+pub fn connect<P: ToSocketAddrs, T: Into<Socks5Target>>(
+    proxy_addr: P,
+    target: T,
+    credentials: Option<&Socks5Credentials>,
+) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect(proxy_addr)?;
+    handshake(&mut stream, credentials)?;
+    request_connect(&mut stream, &target.into())?;
+    Ok(stream)
+}
+
+fn handshake<S: Read + Write>(
+    stream: &mut S,
+    credentials: Option<&Socks5Credentials>,
+) -> io::Result<()> {
+    let methods = if credentials.is_some() {
+        vec![AUTH_NONE, AUTH_USERNAME_PASSWORD]
+    } else {
+        vec![AUTH_NONE]
+    };
+
+    let mut greeting = vec![SOCKS5_VERSION, methods.len() as u8];
+    greeting.extend_from_slice(&methods);
+    stream.write_all(&greeting)?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply)?;
+    if reply[0] != SOCKS5_VERSION {
+        return Err(io::Error::other("unexpected SOCKS5 version in method reply"));
+    }
+
+    match reply[1] {
+        AUTH_NONE => Ok(()),
+        AUTH_USERNAME_PASSWORD => match credentials {
+            Some(credentials) => authenticate(stream, credentials),
+            None => Err(io::Error::other(
+                "SOCKS5 proxy requires username/password authentication",
+            )),
+        },
+        0xFF => Err(io::Error::other(
+            "SOCKS5 proxy rejected all offered authentication methods",
+        )),
+        other => Err(io::Error::other(format!(
+            "SOCKS5 proxy selected unsupported authentication method {other:#04x}"
+        ))),
+    }
+}
+
+fn authenticate<S: Read + Write>(stream: &mut S, credentials: &Socks5Credentials) -> io::Result<()> {
+    let username = credentials.username.as_bytes();
+    let password = credentials.password.as_bytes();
+    if username.len() > 255 || password.len() > 255 {
+        return Err(io::Error::other(
+            "SOCKS5 username/password must each be at most 255 bytes",
+        ));
+    }
+
+    let mut request = vec![0x01, username.len() as u8];
+    request.extend_from_slice(username);
+    request.push(password.len() as u8);
+    request.extend_from_slice(password);
+    stream.write_all(&request)?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply)?;
+    if reply[1] != 0x00 {
+        return Err(io::Error::other("SOCKS5 username/password authentication failed"));
+    }
+    Ok(())
+}
+
+fn request_connect<S: Read + Write>(stream: &mut S, target: &Socks5Target) -> io::Result<()> {
+    let mut request = vec![SOCKS5_VERSION, CMD_CONNECT, 0x00];
+    match target {
+        Socks5Target::Addr(SocketAddr::V4(addr)) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&addr.ip().octets());
+            request.extend_from_slice(&addr.port().to_be_bytes());
+        }
+        Socks5Target::Addr(SocketAddr::V6(addr)) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&addr.ip().octets());
+            request.extend_from_slice(&addr.port().to_be_bytes());
+        }
+        Socks5Target::Domain(domain, port) => {
+            if domain.len() > 255 {
+                return Err(io::Error::other("SOCKS5 domain name must be at most 255 bytes"));
+            }
+            request.push(ATYP_DOMAIN);
+            request.push(domain.len() as u8);
+            request.extend_from_slice(domain.as_bytes());
+            request.extend_from_slice(&port.to_be_bytes());
+        }
+    }
+    stream.write_all(&request)?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    if header[0] != SOCKS5_VERSION {
+        return Err(io::Error::other("unexpected SOCKS5 version in connect reply"));
+    }
+    if header[1] != REPLY_SUCCEEDED {
+        return Err(io::Error::other(format!(
+            "SOCKS5 proxy refused CONNECT request with status {:#04x}",
+            header[1]
+        )));
+    }
+
+    // Drain the bound address the proxy reports back; its contents are not used.
+    match header[3] {
+        ATYP_IPV4 => {
+            let mut bound = [0u8; 4 + 2];
+            stream.read_exact(&mut bound)?;
+        }
+        ATYP_IPV6 => {
+            let mut bound = [0u8; 16 + 2];
+            stream.read_exact(&mut bound)?;
+        }
+        ATYP_DOMAIN => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            let mut bound = vec![0u8; len[0] as usize + 2];
+            stream.read_exact(&mut bound)?;
+        }
+        other => {
+            return Err(io::Error::other(format!(
+                "SOCKS5 proxy replied with unsupported address type {other:#04x}"
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// An in-memory duplex stream standing in for a `TcpStream` in tests: reads come from a
+    /// canned server reply, writes land in a buffer the test can inspect.
+    struct MockStream {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl MockStream {
+        fn with_reply(reply: Vec<u8>) -> Self {
+            Self {
+                input: Cursor::new(reply),
+                output: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for MockStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for MockStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn handshake_no_auth_succeeds() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0x00]);
+        handshake(&mut stream, None).unwrap();
+        assert_eq!(stream.output, vec![0x05, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn handshake_offers_username_password_when_credentials_given() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0x00]);
+        let credentials = Socks5Credentials::new("user", "pass");
+        handshake(&mut stream, Some(&credentials)).unwrap();
+        assert_eq!(stream.output, vec![0x05, 0x02, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn handshake_rejects_all_offered_methods() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0xFF]);
+        let err = handshake(&mut stream, None).unwrap_err();
+        assert!(err.to_string().contains("rejected all offered"));
+    }
+
+    #[test]
+    fn handshake_without_credentials_errors_on_auth_required_reply() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0x02]);
+        let err = handshake(&mut stream, None).unwrap_err();
+        assert!(err.to_string().contains("requires username/password"));
+    }
+
+    #[test]
+    fn handshake_truncated_reply_errors() {
+        let mut stream = MockStream::with_reply(vec![0x05]);
+        assert!(handshake(&mut stream, None).is_err());
+    }
+
+    #[test]
+    fn authenticate_success() {
+        let mut stream = MockStream::with_reply(vec![0x01, 0x00]);
+        let credentials = Socks5Credentials::new("user", "pass");
+        authenticate(&mut stream, &credentials).unwrap();
+        assert_eq!(
+            stream.output,
+            vec![0x01, 4, b'u', b's', b'e', b'r', 4, b'p', b'a', b's', b's']
+        );
+    }
+
+    #[test]
+    fn authenticate_failure_is_reported() {
+        let mut stream = MockStream::with_reply(vec![0x01, 0x01]);
+        let credentials = Socks5Credentials::new("user", "pass");
+        let err = authenticate(&mut stream, &credentials).unwrap_err();
+        assert!(err.to_string().contains("authentication failed"));
+    }
+
+    #[test]
+    fn request_connect_ipv4_success() {
+        let mut stream =
+            MockStream::with_reply(vec![0x05, 0x00, 0x00, ATYP_IPV4, 0, 0, 0, 0, 0, 0]);
+        let target = Socks5Target::Addr("127.0.0.1:514".parse().unwrap());
+        request_connect(&mut stream, &target).unwrap();
+        assert_eq!(stream.output[0..2], [0x05, CMD_CONNECT]);
+    }
+
+    #[test]
+    fn request_connect_domain_target_encodes_length_prefixed_name() {
+        let mut stream =
+            MockStream::with_reply(vec![0x05, 0x00, 0x00, ATYP_IPV4, 0, 0, 0, 0, 0, 0]);
+        let target: Socks5Target = ("collector.example".to_string(), 514u16).into();
+        request_connect(&mut stream, &target).unwrap();
+        assert_eq!(stream.output[3], ATYP_DOMAIN);
+        assert_eq!(stream.output[4], b"collector.example".len() as u8);
+    }
+
+    #[test]
+    fn request_connect_refused_status_errors() {
+        let mut stream =
+            MockStream::with_reply(vec![0x05, 0x01, 0x00, ATYP_IPV4, 0, 0, 0, 0, 0, 0]);
+        let target = Socks5Target::Addr("127.0.0.1:514".parse().unwrap());
+        let err = request_connect(&mut stream, &target).unwrap_err();
+        assert!(err.to_string().contains("refused"));
+    }
+
+    #[test]
+    fn request_connect_unsupported_atyp_in_reply_errors() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0x00, 0x00, 0x99]);
+        let target = Socks5Target::Addr("127.0.0.1:514".parse().unwrap());
+        let err = request_connect(&mut stream, &target).unwrap_err();
+        assert!(err.to_string().contains("unsupported address type"));
+    }
+
+    #[test]
+    fn request_connect_truncated_reply_errors() {
+        let mut stream = MockStream::with_reply(vec![0x05, 0x00]);
+        let target = Socks5Target::Addr("127.0.0.1:514".parse().unwrap());
+        assert!(request_connect(&mut stream, &target).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::io;
+use std::io::BufWriter;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+
+use crate::format::SyslogContext;
+use crate::sender::internal::impl_stream_syslog_sender;
+use crate::sender::internal::impl_syslog_stream_send_formatted;
+use crate::sender::internal::resolver;
+use crate::sender::internal::ConnectSpec;
+use crate::sender::internal::Framing;
+use crate::sender::internal::ReconnectPolicy;
+use crate::sender::internal::Reconnector;
+use crate::sender::socks5;
+use crate::sender::socks5::Socks5Credentials;
+use crate::sender::socks5::Socks5Target;
+
+/// Create a TCP sender that sends messages to the given address.
+pub fn tcp<A: ToSocketAddrs>(addr: A) -> io::Result<TcpSender> {
+    TcpSender::connect(addr)
+}
+
+/// A syslog sender that sends messages to a TCP socket.
+#[derive(Debug)]
+pub struct TcpSender {
+    writer: BufWriter<TcpStream>,
+    context: SyslogContext,
+    framing: Framing,
+    reconnect: Option<Reconnector<TcpStream>>,
+}
+
+impl TcpSender {
+    /// Connect to a TCP socket at the given address.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let addrs: Vec<_> = addr.to_socket_addrs()?.collect();
+        let stream = TcpStream::connect(addrs.as_slice())?;
+        Ok(Self::from_stream(stream))
+    }
+
+    /// Connect to a TCP socket at the given address through a [SOCKS5] proxy.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn tcp_via_proxy<P: ToSocketAddrs, T: Into<Socks5Target>>(
+        proxy_addr: P,
+        target: T,
+    ) -> io::Result<Self> {
+        Self::tcp_via_proxy_with_auth(proxy_addr, target, None)
+    }
+
+    /// Connect to a TCP socket through a [SOCKS5] proxy that requires username/password
+    /// authentication.
+    ///
+    /// [SOCKS5]: https://datatracker.ietf.org/doc/html/rfc1928
+    pub fn tcp_via_proxy_with_auth<P: ToSocketAddrs, T: Into<Socks5Target>>(
+        proxy_addr: P,
+        target: T,
+        credentials: Option<Socks5Credentials>,
+    ) -> io::Result<Self> {
+        let proxy_addrs: Vec<_> = proxy_addr.to_socket_addrs()?.collect();
+        let target = target.into();
+        let stream = socks5::connect(proxy_addrs.as_slice(), target.clone(), credentials.as_ref())?;
+        Ok(Self::from_stream(stream))
+    }
+
+    fn from_stream(stream: TcpStream) -> Self {
+        Self {
+            writer: BufWriter::new(stream),
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\n")),
+            reconnect: None,
+        }
+    }
+
+    /// Set the postfix appended to each message under non-transparent framing.
+    pub fn set_postfix(&mut self, postfix: impl Into<Cow<'static, str>>) {
+        self.framing = Framing::NonTransparent(postfix.into());
+    }
+
+    /// Set how successive messages are delimited on the wire, per [RFC-6587].
+    ///
+    /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+    pub fn set_framing(&mut self, framing: Framing) {
+        self.framing = framing;
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error.
+    ///
+    /// Reconnecting redials `addr`, re-resolving it on every attempt so reconnects pick up DNS
+    /// changes instead of redialing a frozen, possibly stale address. `addr` need not be the
+    /// same value passed to [`TcpSender::connect`], but should resolve to the same destination.
+    pub fn set_reconnect_policy<A: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        addr: A,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Direct(resolver(addr));
+        self.reconnect = Some(Reconnector::new(policy, move || connect_spec.dial()));
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error, for a
+    /// sender constructed via [`TcpSender::tcp_via_proxy`] or
+    /// [`TcpSender::tcp_via_proxy_with_auth`].
+    ///
+    /// Reconnecting redials `proxy_addr` and redoes the SOCKS5 CONNECT to `target`, re-resolving
+    /// `proxy_addr` on every attempt so reconnects pick up DNS changes.
+    pub fn set_reconnect_policy_via_proxy<P: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        &mut self,
+        proxy_addr: P,
+        target: Socks5Target,
+        credentials: Option<Socks5Credentials>,
+        policy: ReconnectPolicy,
+    ) {
+        let connect_spec = ConnectSpec::Proxy(resolver(proxy_addr), target, credentials);
+        self.reconnect = Some(Reconnector::new(policy, move || connect_spec.dial()));
+    }
+}
+
+impl_stream_syslog_sender!(TcpSender, writer);
+impl_syslog_stream_send_formatted!(TcpSender, writer);
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Severity;
+
+    #[test]
+    fn reconnect_redials_and_delivers_after_peer_drop() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let mut sender = TcpSender::connect(addr).unwrap();
+        sender.set_reconnect_policy(
+            addr,
+            ReconnectPolicy::new()
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_max_backoff(Duration::from_millis(5))
+                .with_max_attempts(5),
+        );
+
+        // Accept and immediately drop the first connection, simulating the peer going away.
+        let (first, _) = listener.accept().unwrap();
+        drop(first);
+
+        let accept_second = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            stream.read_to_end(&mut received).unwrap();
+            received
+        });
+
+        // Reconnection is transparent to the caller: `write_with_reconnect`/`flush` detect the
+        // dead connection and redial internally, so `send_rfc3164`/`flush` keep returning `Ok`
+        // across the drop. Send a handful of times (one of which will hit the dead connection
+        // and trigger a reconnect); the receiving thread only finishes once the sender is
+        // dropped and the second connection closes.
+        for _ in 0..20 {
+            let _ = sender.send_rfc3164(Severity::Info, "hello");
+            let _ = sender.flush();
+            if accept_second.is_finished() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(2));
+        }
+        drop(sender);
+
+        let received = String::from_utf8(accept_second.join().unwrap()).unwrap();
+        assert!(received.contains("hello"), "missing message: {received}");
+    }
+}
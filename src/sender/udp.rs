@@ -19,7 +19,7 @@ use std::net::UdpSocket;
 use std::str::FromStr;
 
 use crate::format::SyslogContext;
-use crate::sender::internal::impl_syslog_sender_common;
+use crate::sender::internal::impl_datagram_syslog_sender;
 
 /// Create a UDP sender that sends messages to the well-known port (514).
 ///
@@ -84,7 +84,7 @@ impl UdpSender {
     }
 }
 
-impl_syslog_sender_common!(UdpSender);
+impl_datagram_syslog_sender!(UdpSender, socket);
 
 /// A syslog sender that sends messages to a UDP socket.
 #[derive(Debug)]
@@ -123,4 +123,4 @@ impl BroadcastSender {
     }
 }
 
-impl_syslog_sender_common!(BroadcastSender);
+impl_datagram_syslog_sender!(BroadcastSender, socket);
@@ -0,0 +1,145 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::io;
+use std::io::BufWriter;
+use std::os::unix::net::UnixDatagram;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::format::SyslogContext;
+use crate::sender::internal::impl_datagram_syslog_sender;
+use crate::sender::internal::impl_stream_syslog_sender;
+use crate::sender::internal::impl_syslog_stream_send_formatted;
+use crate::sender::internal::Framing;
+use crate::sender::internal::ReconnectPolicy;
+use crate::sender::internal::Reconnector;
+
+/// Create a sender that sends messages to the platform's default Unix syslog socket
+/// (`/dev/log` on Linux, `/var/run/syslog` on macOS) over a datagram socket.
+pub fn unix_datagram_well_known() -> io::Result<UnixDatagramSender> {
+    #[cfg(target_os = "macos")]
+    const DEFAULT_PATH: &str = "/var/run/syslog";
+    #[cfg(not(target_os = "macos"))]
+    const DEFAULT_PATH: &str = "/dev/log";
+
+    unix_datagram(DEFAULT_PATH)
+}
+
+/// Create a sender that sends messages to the Unix datagram socket at the given path.
+pub fn unix_datagram<P: AsRef<Path>>(path: P) -> io::Result<UnixDatagramSender> {
+    UnixDatagramSender::connect(path)
+}
+
+/// Create a sender that sends messages to the Unix stream socket at the given path.
+pub fn unix_stream<P: AsRef<Path>>(path: P) -> io::Result<UnixStreamSender> {
+    UnixStreamSender::connect(path)
+}
+
+/// A syslog sender that sends messages to a Unix datagram socket.
+#[derive(Debug)]
+pub struct UnixDatagramSender {
+    socket: UnixDatagram,
+    context: SyslogContext,
+}
+
+impl UnixDatagramSender {
+    /// Connect to a Unix datagram socket at the given path.
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(path)?;
+        Ok(Self {
+            socket,
+            context: SyslogContext::default(),
+        })
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+
+    /// Send a pre-formatted message.
+    pub fn send_formatted(&mut self, formatted: &[u8]) -> io::Result<()> {
+        self.socket.send(formatted)?;
+        Ok(())
+    }
+}
+
+impl_datagram_syslog_sender!(UnixDatagramSender, socket);
+
+/// A syslog sender that sends messages to a Unix stream socket.
+#[derive(Debug)]
+pub struct UnixStreamSender {
+    writer: BufWriter<UnixStream>,
+    context: SyslogContext,
+    framing: Framing,
+    path: PathBuf,
+    reconnect: Option<Reconnector<UnixStream>>,
+}
+
+impl UnixStreamSender {
+    /// Connect to a Unix stream socket at the given path.
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let stream = UnixStream::connect(&path)?;
+        Ok(Self {
+            writer: BufWriter::new(stream),
+            context: SyslogContext::default(),
+            framing: Framing::NonTransparent(Cow::Borrowed("\n")),
+            path,
+            reconnect: None,
+        })
+    }
+
+    /// Set the postfix appended to each message under non-transparent framing.
+    pub fn set_postfix(&mut self, postfix: impl Into<Cow<'static, str>>) {
+        self.framing = Framing::NonTransparent(postfix.into());
+    }
+
+    /// Set how successive messages are delimited on the wire, per [RFC-6587].
+    ///
+    /// [RFC-6587]: https://datatracker.ietf.org/doc/html/rfc6587
+    pub fn set_framing(&mut self, framing: Framing) {
+        self.framing = framing;
+    }
+
+    /// Set the context when formatting Syslog message.
+    pub fn set_context(&mut self, context: SyslogContext) {
+        self.context = context;
+    }
+
+    /// Mutate the context when formatting Syslog message.
+    pub fn mut_context(&mut self) -> &mut SyslogContext {
+        &mut self.context
+    }
+
+    /// Enable transparent reconnection, per `policy`, when a write hits a connection error.
+    ///
+    /// Reconnecting reopens the same socket path this sender was originally constructed with.
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        let path = self.path.clone();
+        self.reconnect = Some(Reconnector::new(policy, move || UnixStream::connect(&path)));
+    }
+}
+
+impl_stream_syslog_sender!(UnixStreamSender, writer);
+impl_syslog_stream_send_formatted!(UnixStreamSender, writer);
@@ -0,0 +1,98 @@
+// Copyright 2024 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// The severity of a syslog message, per [RFC-5424] ยง6.2.1 (Table 2).
+///
+/// Variants are declared from most to least severe, matching their numeric codes, so derived
+/// [`Ord`] comparisons (`Severity::Error < Severity::Debug`) agree with the RFC.
+///
+/// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// System is unusable.
+    Emergency,
+    /// Action must be taken immediately.
+    Alert,
+    /// Critical conditions.
+    Critical,
+    /// Error conditions.
+    Error,
+    /// Warning conditions.
+    Warning,
+    /// Normal but significant conditions.
+    Notice,
+    /// Informational messages.
+    Info,
+    /// Debug-level messages.
+    Debug,
+}
+
+impl Severity {
+    /// The numeric severity code, per [RFC-5424] Table 2.
+    ///
+    /// [RFC-5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.1
+    pub fn code(self) -> u8 {
+        match self {
+            Severity::Emergency => 0,
+            Severity::Alert => 1,
+            Severity::Critical => 2,
+            Severity::Error => 3,
+            Severity::Warning => 4,
+            Severity::Notice => 5,
+            Severity::Info => 6,
+            Severity::Debug => 7,
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Emergency => "emerg",
+            Severity::Alert => "alert",
+            Severity::Critical => "crit",
+            Severity::Error => "err",
+            Severity::Warning => "warning",
+            Severity::Notice => "notice",
+            Severity::Info => "info",
+            Severity::Debug => "debug",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_matches_rfc_5424_table_2() {
+        assert_eq!(Severity::Emergency.code(), 0);
+        assert_eq!(Severity::Alert.code(), 1);
+        assert_eq!(Severity::Critical.code(), 2);
+        assert_eq!(Severity::Error.code(), 3);
+        assert_eq!(Severity::Warning.code(), 4);
+        assert_eq!(Severity::Notice.code(), 5);
+        assert_eq!(Severity::Info.code(), 6);
+        assert_eq!(Severity::Debug.code(), 7);
+    }
+
+    #[test]
+    fn ordered_from_most_to_least_severe() {
+        assert!(Severity::Emergency < Severity::Error);
+        assert!(Severity::Error < Severity::Debug);
+    }
+}